@@ -3,7 +3,7 @@ use clap::builder::styling::{AnsiColor, Effects};
 use clap::builder::Styles;
 use clap::{arg, value_parser, Parser};
 
-use crate::wrappers::{CliByteOrder, CliCompressor, CliVtkFormat};
+use crate::wrappers::{CliByteOrder, CliCompressor, CliDataEncoding, CliVtkFormat};
 
 /// Generalised conversion of meshtal files to visual toolkit formats
 ///
@@ -13,6 +13,9 @@ use crate::wrappers::{CliByteOrder, CliCompressor, CliVtkFormat};
 ///  Typical use:
 ///     $ mesh2vtk my_file.msht 104 -o my_output
 ///
+///  Convert every tally found in the file:
+///     $ mesh2vtk my_file.msht --all
+///
 ///  Extract only the 'Total' energy and time groups:
 ///     $ mesh2vtk /path/to/file.msht 104 --total
 ///
@@ -24,6 +27,11 @@ use crate::wrappers::{CliByteOrder, CliCompressor, CliVtkFormat};
 ///               --energy 0 2 6           \
 ///               --time 1 total
 ///
+///  Filter energy/time groups by range:
+///     $ mesh2vtk /path/to/file.msht 104  \
+///               --energy 2-8             \
+///               --time 0-10:2 -3
+///
 ///  Filter energy/time groups by value:
 ///     $ mesh2vtk /path/to/file.msht 104  \
 ///               --energy 1.0 20.0 1e2    \
@@ -36,7 +44,11 @@ use crate::wrappers::{CliByteOrder, CliCompressor, CliVtkFormat};
 ///
 ///  Alter basic mesh properties:
 ///     $ mesh2vtk /path/to/file.msht 104  \
-///               --scale 1.0                 
+///               --scale 1.0
+///
+///  Mask voxels outside a value window:
+///     $ mesh2vtk /path/to/file.msht 104  \
+///               --min 1.0e-06 --max 1.0
 ///
 /// Notes
 /// -----
@@ -58,7 +70,7 @@ use crate::wrappers::{CliByteOrder, CliCompressor, CliVtkFormat};
     after_help("Note: --help shows more information and examples"),
     term_width(76),
     hide_possible_values(true),
-    override_usage("mesh2vtk <file> <id> [options]"),
+    override_usage("mesh2vtk <file> [id] [options]"),
     styles=custom_style()
 )]
 pub struct Cli {
@@ -70,10 +82,26 @@ pub struct Cli {
     /// Mesh tally identifier
     ///
     /// e.g. 104 for FMESH104:n
+    ///
+    /// Required unless --all is given, in which case every tally found in
+    /// the file is converted. Giving both an id and --all is an error.
     #[arg(name = "number")]
-    pub number: u32,
+    #[arg(required_unless_present = "all")]
+    pub number: Option<u32>,
 
     // * Optional
+    /// Convert every tally found in the file
+    ///
+    /// Runs a batch conversion over every FMESH tally in the meshtal file
+    /// instead of a single targeted mesh, applying the same group
+    /// filtering/format/compressor options to each. Output file names are
+    /// disambiguated automatically since `--output` always has the mesh id
+    /// appended.
+    #[arg(help_heading("Mesh options"))]
+    #[arg(long)]
+    #[arg(conflicts_with = "number")]
+    pub all: bool,
+
     /// Only extract 'Total' energy/time groups
     ///
     /// By default all energy groups are included in the vtk. This equivalent to
@@ -100,11 +128,39 @@ pub struct Cli {
     #[arg(value_name = "num")]
     pub scale: Option<f64>,
 
+    /// Mask voxels below this value
+    ///
+    /// Voxels with a result below the value provided are replaced with NaN
+    /// rather than dropped, so the geometry/index mapping is left intact.
+    /// ParaView and VisIt render NaN voxels as "no data". Errors are
+    /// untouched, exactly as with --scale.
+    #[arg(help_heading("Mesh options"))]
+    #[arg(long)]
+    #[arg(value_name = "val")]
+    pub min: Option<f64>,
+
+    /// Mask voxels above this value
+    ///
+    /// Voxels with a result above the value provided are replaced with NaN
+    /// rather than dropped, so the geometry/index mapping is left intact.
+    /// ParaView and VisIt render NaN voxels as "no data". Errors are
+    /// untouched, exactly as with --scale.
+    #[arg(help_heading("Mesh options"))]
+    #[arg(long)]
+    #[arg(value_name = "val")]
+    pub max: Option<f64>,
+
     /// Filter energy group(s)
     ///
     /// By default all energy groups are included in the vtk. Specific energy
     /// groups can be specified by index. Values may be any combination of
-    /// positive integers and the word 'total'.
+    /// positive integers, compact ranges, and the word 'total'.
+    ///
+    /// Ranges may be given as `start-end` (inclusive) and optionally strided
+    /// with `start-end:stride`. A bare `-n` means "last n groups".
+    ///   - e.g. "2-8" is equivalent to "2 3 4 5 6 7 8"
+    ///   - e.g. "0-10:2" is equivalent to "0 2 4 6 8 10"
+    ///   - e.g. "-3" is the last three groups
     ///
     /// For filtering by real energy values in MeV rather than group index, use
     /// the --absolute falg.
@@ -114,13 +170,20 @@ pub struct Cli {
     #[clap(required = false)]
     #[arg(conflicts_with = "total")]
     #[arg(value_name = "list")]
+    #[arg(allow_negative_numbers(true))]
     pub energy: Vec<String>,
 
     /// Filter time group(s)
     ///
     /// By default all time groups are included in the vtk. Specific time
     /// groups can be specified by index. Values may be any combination of
-    /// positive integers and the word 'total'.
+    /// positive integers, compact ranges, and the word 'total'.
+    ///
+    /// Ranges may be given as `start-end` (inclusive) and optionally strided
+    /// with `start-end:stride`. A bare `-n` means "last n groups".
+    ///   - e.g. "2-8" is equivalent to "2 3 4 5 6 7 8"
+    ///   - e.g. "0-10:2" is equivalent to "0 2 4 6 8 10"
+    ///   - e.g. "-3" is the last three groups
     ///
     /// For filtering by real time values in shakes rather than group index, use
     /// the --absolute flag.
@@ -217,6 +280,37 @@ pub struct Cli {
     #[arg(value_name = "cmp")]
     pub compressor: CliCompressor,
 
+    /// Data array layout for xml vtk output
+    ///
+    /// Appended layouts write every array payload once as a single blob at
+    /// the end of the file instead of inlining each array, which is
+    /// smaller and faster to write for large `.vtu`/`.vtr` outputs.
+    ///     > inline-base64 (default)
+    ///     > appended-base64
+    ///     > appended-raw
+    ///
+    /// The appended-raw variant still honours the chosen --endian and
+    /// --compressor.
+    #[arg(long, value_enum)]
+    #[arg(help_heading("Vtk options"))]
+    #[arg(hide_default_value(true))]
+    #[arg(default_value_t = CliDataEncoding::InlineBase64)]
+    #[arg(verbatim_doc_comment)]
+    #[arg(value_name = "enc")]
+    pub encoding: CliDataEncoding,
+
+    /// Number of parallel jobs for --all conversions
+    ///
+    /// Converting and writing each mesh is independent and CPU-bound, so
+    /// --all batches are processed across a worker pool. Defaults to the
+    /// number of available cores. Use `--jobs 1` to force serial
+    /// conversion, e.g. for easier log reading.
+    #[arg(help_heading("Vtk options"))]
+    #[arg(long)]
+    #[arg(value_name = "n")]
+    #[arg(value_parser = value_parser!(usize).range(1..))]
+    pub jobs: Option<usize>,
+
     // * Flags
     /// Verbose logging (-v, -vv)
     ///
@@ -17,6 +17,7 @@ use ntools::utils::f;
 // external
 use anyhow::Result;
 use log::{debug, info, trace, warn};
+use rayon::prelude::*;
 
 /// Sets up logging at runtime to allow for multiple verbosity levels
 pub fn init_logging(cli: &Cli) -> Result<()> {
@@ -32,13 +33,103 @@ pub fn init_logging(cli: &Cli) -> Result<()> {
 
 /// Attempts to read a single targeted mesh from the file
 pub fn try_meshtal_read(cli: &Cli) -> Result<Mesh> {
+    let number = cli.number.expect("number required unless --all is set");
     if cli.quiet {
-        Ok(ntools::mesh::read_target(&cli.file, cli.number)?)
+        Ok(ntools::mesh::read_target(&cli.file, number)?)
     } else {
-        Ok(ntools::mesh::read_target_pb(&cli.file, cli.number)?)
+        Ok(ntools::mesh::read_target_pb(&cli.file, number)?)
     }
 }
 
+/// Attempts to read every mesh tally found in the file
+pub fn try_meshtal_read_all(cli: &Cli) -> Result<Vec<Mesh>> {
+    if cli.quiet {
+        Ok(ntools::mesh::read_meshtal(&cli.file)?)
+    } else {
+        Ok(ntools::mesh::read_meshtal_pb(&cli.file)?)
+    }
+}
+
+/// Scale, convert, and write a single mesh to a VTK file
+pub fn convert_and_write(mesh: &mut Mesh, cli: &Cli) -> Result<()> {
+    debug!("Mesh summary\n{mesh}");
+
+    if let Some(scale) = cli.scale {
+        debug!("Scaling mesh {} results by {:.5e}", mesh.id, scale);
+        mesh.scale(scale);
+    }
+
+    if cli.min.is_some() || cli.max.is_some() {
+        mask_voxels(mesh, cli.min, cli.max);
+    }
+
+    debug!("Initialising converter for mesh {}", mesh.id);
+    let convertor = init_converter(mesh, cli);
+
+    debug!("Converting mesh {} to VTK object", mesh.id);
+    let vtk = convertor.convert(mesh);
+
+    let path = output_path(mesh, cli);
+    info!("Writing mesh {} to {path:?}", mesh.id);
+    Ok(ntools::mesh::write_vtk(vtk, path, cli.format.into())?)
+}
+
+/// Scale, convert, and write every mesh in `meshes` across a worker pool
+///
+/// One job per core is used by default since VTK generation and
+/// compression are independent and CPU-bound per mesh. Pass `--jobs 1` to
+/// force serial conversion instead, e.g. for easier log reading.
+///
+/// Lines from concurrent jobs may interleave with each other, but
+/// `stderrlog` serialises each individual write behind a lock. The lines
+/// logged by `convert_and_write` itself name the mesh id; those logged
+/// while setting up the converter (resolution, error exclusion, group
+/// selection warnings) do not, so with `--jobs` > 1 they cannot be told
+/// apart by mesh.
+pub fn convert_all(meshes: &mut [Mesh], cli: &Cli) -> Result<()> {
+    let jobs = cli.jobs.unwrap_or_else(|| {
+        std::thread::available_parallelism()
+            .map(usize::from)
+            .unwrap_or(1)
+    });
+    debug!("Converting with {jobs} job(s)");
+
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()?
+        .install(|| {
+            meshes
+                .par_iter_mut()
+                .try_for_each(|mesh| convert_and_write(mesh, cli))
+        })
+}
+
+/// Mask voxels outside the `[min, max]` value window with NaN
+///
+/// Masked voxels are not dropped, so the geometry/index mapping is left
+/// intact. ParaView and VisIt both render NaN voxels as "no data".
+fn mask_voxels(mesh: &mut Mesh, min: Option<f64>, max: Option<f64>) {
+    let mut kept = 0;
+    let mut masked = 0;
+
+    for voxel in mesh.voxels.iter_mut() {
+        let outside =
+            min.is_some_and(|min| voxel.result < min) || max.is_some_and(|max| voxel.result > max);
+
+        if outside {
+            voxel.result = f64::NAN;
+            masked += 1;
+        } else {
+            kept += 1;
+        }
+    }
+
+    info!(
+        "Mesh {}: kept {kept} voxels, masked {masked} outside [{min:?}, {max:?}]",
+        mesh.id
+    );
+}
+
 /// Sanitise the output given and append the mesh tally id
 pub fn output_path(mesh: &Mesh, cli: &Cli) -> PathBuf {
     let mut path = PathBuf::from(&cli.output);
@@ -84,6 +175,7 @@ pub fn init_converter(mesh: &Mesh, cli: &Cli) -> MeshToVtk {
         .include_errors(!cli.no_error)
         .byte_order(cli.endian.into())
         .compressor(cli.compressor.into())
+        .encoding(cli.encoding.into())
         .resolution(cli.resolution.unwrap_or(1))
         .energy_groups(energies)
         .time_groups(times)
@@ -117,7 +209,7 @@ fn index_set(targets: &[String], total_idx: usize) -> Vec<usize> {
         return (0..total_idx + 1).collect();
     }
 
-    let mut indicies = targets_to_usize(targets);
+    let mut indicies = targets_to_usize(targets, total_idx);
     if targets.iter().any(|t| t.to_lowercase() == "total") {
         indicies.push(total_idx)
     };
@@ -134,13 +226,50 @@ fn index_set(targets: &[String], total_idx: usize) -> Vec<usize> {
     indicies
 }
 
-fn targets_to_usize(targets: &[String]) -> Vec<usize> {
+/// Expand bare integers, `start-end` ranges, `start-end:stride` strided
+/// ranges, and `-n` "last n groups" tokens into a flat list of indicies
+fn targets_to_usize(targets: &[String], total_idx: usize) -> Vec<usize> {
     targets
         .iter()
-        .filter_map(|group| group.parse::<usize>().ok())
+        .flat_map(|group| expand_index_token(group, total_idx))
         .collect()
 }
 
+/// Expand a single index token against the known total group index
+fn expand_index_token(token: &str, total_idx: usize) -> Vec<usize> {
+    let token = token.trim();
+
+    // bare integer, e.g. "6"
+    if let Ok(index) = token.parse::<usize>() {
+        return vec![index];
+    }
+
+    // optional ":stride" suffix, e.g. "0-10:2"
+    let (span, stride) = match token.split_once(':') {
+        Some((span, stride)) => (span, stride.parse::<usize>().unwrap_or(1).max(1)),
+        None => (token, 1),
+    };
+
+    // "-3" relative to the total index means "last three groups"
+    if let Some(count) = span.strip_prefix('-') {
+        return match count.parse::<usize>() {
+            Ok(0) | Err(_) => Vec::new(),
+            Ok(n) => (total_idx.saturating_sub(n - 1)..=total_idx)
+                .step_by(stride)
+                .collect(),
+        };
+    }
+
+    // "2-8" inclusive range, clamped to the known total index
+    if let Some((start, end)) = span.split_once('-') {
+        if let (Ok(start), Ok(end)) = (start.parse::<usize>(), end.parse::<usize>()) {
+            return (start..=end.min(total_idx)).step_by(stride).collect();
+        }
+    }
+
+    Vec::new()
+}
+
 fn parse_as_absolute(mesh: &Mesh, cli: &Cli) -> (Vec<usize>, Vec<usize>) {
     debug!("Parsing energy/time groups as absolute values");
     let energies = if cli.energy.is_empty() {
@@ -3,12 +3,11 @@
 
 // standard library
 use mesh2vtk::cli::Cli;
-use ntools::mesh;
 
 // external crates
 use anyhow::Result;
 use clap::Parser;
-use log::{debug, info};
+use log::info;
 
 fn main() -> Result<()> {
     // set up the command line interface and logging
@@ -17,25 +16,14 @@ fn main() -> Result<()> {
 
     // Get the mesh tallies
     info!("Reading {}", &cli.file);
-    let mut mesh = mesh2vtk::try_meshtal_read(&cli)?;
-    debug!("Mesh summary\n{mesh}");
-
-    // Scale if needed
-    if let Some(scale) = cli.scale {
-        info!("Scaling results by {:.5e}", scale);
-        mesh.scale(scale);
+    if cli.all {
+        let mut meshes = mesh2vtk::try_meshtal_read_all(&cli)?;
+        info!("Converting {} tallies found in file", meshes.len());
+        mesh2vtk::convert_all(&mut meshes, &cli)?;
+    } else {
+        let mut mesh = mesh2vtk::try_meshtal_read(&cli)?;
+        mesh2vtk::convert_and_write(&mut mesh, &cli)?;
     }
 
-    // Generate the vtk and write to file
-    debug!("Initialising converter");
-    let convertor = mesh2vtk::init_converter(&mesh, &cli);
-
-    info!("Converting mesh to VTK object");
-    let vtk = convertor.convert(&mesh);
-
-    info!("Writing VTK to file");
-    let path = mesh2vtk::output_path(&mesh, &cli);
-    mesh::write_vtk(vtk, path, cli.format.into())?;
-
     Ok(())
 }
@@ -0,0 +1,124 @@
+//! Thin CLI-facing wrappers around the equivalent `ntools` enums
+//!
+//! `clap::ValueEnum` must be implemented on a type local to this crate, so
+//! these mirror the `ntools` enums one-to-one and convert with `From`.
+
+use std::fmt;
+
+use clap::ValueEnum;
+
+use ntools::mesh::vtk::{ByteOrder, Compressor, DataArrayEncoding};
+use ntools::mesh::VtkFormat;
+
+/// Byte ordering (endian) for vtk output
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CliByteOrder {
+    BigEndian,
+    LittleEndian,
+}
+
+impl From<CliByteOrder> for ByteOrder {
+    fn from(order: CliByteOrder) -> Self {
+        match order {
+            CliByteOrder::BigEndian => ByteOrder::BigEndian,
+            CliByteOrder::LittleEndian => ByteOrder::LittleEndian,
+        }
+    }
+}
+
+impl fmt::Display for CliByteOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+/// Compression method for xml vtk output
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CliCompressor {
+    LZMA,
+    LZ4,
+    Zlib,
+    None,
+}
+
+impl From<CliCompressor> for Compressor {
+    fn from(compressor: CliCompressor) -> Self {
+        match compressor {
+            CliCompressor::LZMA => Compressor::LZMA,
+            CliCompressor::LZ4 => Compressor::LZ4,
+            CliCompressor::Zlib => Compressor::Zlib,
+            CliCompressor::None => Compressor::None,
+        }
+    }
+}
+
+impl fmt::Display for CliCompressor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+/// Output vtk file format
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CliVtkFormat {
+    Xml,
+    LegacyAscii,
+    LegacyBinary,
+}
+
+impl From<CliVtkFormat> for VtkFormat {
+    fn from(format: CliVtkFormat) -> Self {
+        match format {
+            CliVtkFormat::Xml => VtkFormat::Xml,
+            CliVtkFormat::LegacyAscii => VtkFormat::LegacyAscii,
+            CliVtkFormat::LegacyBinary => VtkFormat::LegacyBinary,
+        }
+    }
+}
+
+impl fmt::Display for CliVtkFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+/// Data array layout for xml vtk output
+///
+/// Inline arrays are base64 encoded in place. Appended arrays are instead
+/// written once as a single blob at the end of the file, which is smaller
+/// and faster for the large arrays that cylindrical `--resolution` meshes
+/// generate.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum CliDataEncoding {
+    InlineBase64,
+    AppendedBase64,
+    AppendedRaw,
+}
+
+impl From<CliDataEncoding> for DataArrayEncoding {
+    fn from(encoding: CliDataEncoding) -> Self {
+        match encoding {
+            CliDataEncoding::InlineBase64 => DataArrayEncoding::InlineBase64,
+            CliDataEncoding::AppendedBase64 => DataArrayEncoding::AppendedBase64,
+            CliDataEncoding::AppendedRaw => DataArrayEncoding::AppendedRaw,
+        }
+    }
+}
+
+impl fmt::Display for CliDataEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}